@@ -0,0 +1,329 @@
+//! Periodic background job that refreshes seeders/leechers for every known torrent.
+//!
+//! `get_torrents` sorts on whatever `seeders`/`leechers` are stored in `torrust_torrents`,
+//! and only `get_torrent` refreshes a single listing live via the tracker's HTTP API. Doing
+//! that per-row for a full index page would mean one tracker round trip per result, so
+//! instead this task batches every known info-hash and scrapes them all over the tracker's
+//! UDP scrape endpoint (BEP15), writing the results straight into the database. Spawned once
+//! at startup (see `main.rs`) with `settings.tracker.bulk_scrape_interval` controlling how
+//! often it runs.
+
+use crate::common::WebAppData;
+use crate::models::info_hash::InfoHash;
+use rand::random;
+use std::io;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+use tokio::time::timeout;
+
+const PROTOCOL_ID: u64 = 0x0417_2710_1980;
+const ACTION_CONNECT: u32 = 0;
+const ACTION_SCRAPE: u32 = 2;
+// BEP15 caps a single scrape at about this many hashes: the request is then 16 + 74 * 20 =
+// 1496 bytes and the response 8 + 74 * 12 = 896 bytes
+const MAX_INFO_HASHES_PER_SCRAPE: usize = 74;
+// connection ids expire two minutes after they're issued and must be re-established
+const CONNECTION_ID_TTL: Duration = Duration::from_secs(120);
+const UDP_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct TorrentScrapeResult {
+    pub info_hash: InfoHash,
+    pub seeders: i64,
+    pub completed: i64,
+    pub leechers: i64,
+}
+
+struct Connection {
+    id: u64,
+    obtained_at: Instant,
+}
+
+/// Spawns the bulk-scrape loop. Runs forever on the current actix runtime, sleeping
+/// `interval` between rounds and tolerating an unreachable or slow tracker without
+/// taking the loop down.
+pub fn spawn(app_data: WebAppData, interval: Duration) {
+    actix_web::rt::spawn(async move {
+        let mut connection: Option<Connection> = None;
+
+        loop {
+            actix_web::rt::time::sleep(interval).await;
+
+            let info_hashes = match app_data.database.get_all_info_hashes().await {
+                Ok(hashes) => hashes,
+                Err(e) => {
+                    println!("bulk_scrape: failed to load info hashes: {:?}", e);
+                    continue;
+                }
+            };
+
+            if info_hashes.is_empty() {
+                continue;
+            }
+
+            let settings = app_data.cfg.settings.read().await;
+            let udp_addr = settings.tracker.udp_url.clone();
+            drop(settings);
+
+            let socket = match UdpSocket::bind("0.0.0.0:0").await {
+                Ok(socket) => socket,
+                Err(e) => {
+                    println!("bulk_scrape: failed to bind udp socket: {:?}", e);
+                    continue;
+                }
+            };
+
+            if let Err(e) = socket.connect(&udp_addr).await {
+                println!("bulk_scrape: failed to connect to tracker {}: {:?}", udp_addr, e);
+                continue;
+            }
+
+            // stamped on every row written this round so stale entries (failed/skipped
+            // scrapes) can be told apart from ones just refreshed
+            let last_scraped = unix_timestamp();
+
+            for chunk in info_hashes.chunks(MAX_INFO_HASHES_PER_SCRAPE) {
+                match scrape_chunk(&socket, &mut connection, chunk).await {
+                    Ok(results) => {
+                        for result in results {
+                            let _ = app_data
+                                .database
+                                .update_torrent_scrape_stats(
+                                    result.info_hash,
+                                    result.seeders,
+                                    result.completed,
+                                    result.leechers,
+                                    last_scraped,
+                                )
+                                .await;
+                        }
+                    }
+                    Err(e) => {
+                        println!("bulk_scrape: scrape round failed: {:?}", e);
+                        // the connection id may have been the problem; force a reconnect
+                        connection = None;
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn scrape_chunk(
+    socket: &UdpSocket,
+    connection: &mut Option<Connection>,
+    info_hashes: &[InfoHash],
+) -> io::Result<Vec<TorrentScrapeResult>> {
+    if connection
+        .as_ref()
+        .map(|c| c.obtained_at.elapsed() >= CONNECTION_ID_TTL)
+        .unwrap_or(true)
+    {
+        *connection = Some(Connection {
+            id: connect(socket).await?,
+            obtained_at: Instant::now(),
+        });
+    }
+
+    let connection_id = connection.as_ref().unwrap().id;
+
+    scrape(socket, connection_id, info_hashes).await
+}
+
+async fn connect(socket: &UdpSocket) -> io::Result<u64> {
+    let transaction_id: u32 = random();
+
+    let mut request = Vec::with_capacity(16);
+    request.extend_from_slice(&PROTOCOL_ID.to_be_bytes());
+    request.extend_from_slice(&ACTION_CONNECT.to_be_bytes());
+    request.extend_from_slice(&transaction_id.to_be_bytes());
+
+    send_with_timeout(socket, &request).await?;
+
+    let mut buf = [0u8; 16];
+    recv_with_timeout(socket, &mut buf).await?;
+
+    let action = u32::from_be_bytes(buf[0..4].try_into().unwrap());
+    let received_transaction_id = u32::from_be_bytes(buf[4..8].try_into().unwrap());
+    if action != ACTION_CONNECT || received_transaction_id != transaction_id {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected connect response"));
+    }
+
+    Ok(u64::from_be_bytes(buf[8..16].try_into().unwrap()))
+}
+
+async fn scrape(
+    socket: &UdpSocket,
+    connection_id: u64,
+    info_hashes: &[InfoHash],
+) -> io::Result<Vec<TorrentScrapeResult>> {
+    let transaction_id: u32 = random();
+
+    let mut request = Vec::with_capacity(16 + info_hashes.len() * 20);
+    request.extend_from_slice(&connection_id.to_be_bytes());
+    request.extend_from_slice(&ACTION_SCRAPE.to_be_bytes());
+    request.extend_from_slice(&transaction_id.to_be_bytes());
+    for info_hash in info_hashes {
+        request.extend_from_slice(&info_hash.0);
+    }
+
+    send_with_timeout(socket, &request).await?;
+
+    let mut buf = vec![0u8; 8 + info_hashes.len() * 12];
+    let n = recv_with_timeout(socket, &mut buf).await?;
+    let buf = &buf[..n];
+
+    if buf.len() < 8 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "truncated scrape response"));
+    }
+
+    let action = u32::from_be_bytes(buf[0..4].try_into().unwrap());
+    let received_transaction_id = u32::from_be_bytes(buf[4..8].try_into().unwrap());
+    if action != ACTION_SCRAPE || received_transaction_id != transaction_id {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected scrape response"));
+    }
+
+    let mut results = Vec::with_capacity(info_hashes.len());
+    for (i, info_hash) in info_hashes.iter().enumerate() {
+        let offset = 8 + i * 12;
+        if offset + 12 > buf.len() {
+            break;
+        }
+        results.push(TorrentScrapeResult {
+            info_hash: *info_hash,
+            seeders: u32::from_be_bytes(buf[offset..offset + 4].try_into().unwrap()) as i64,
+            completed: u32::from_be_bytes(buf[offset + 4..offset + 8].try_into().unwrap()) as i64,
+            leechers: u32::from_be_bytes(buf[offset + 8..offset + 12].try_into().unwrap()) as i64,
+        });
+    }
+
+    Ok(results)
+}
+
+async fn send_with_timeout(socket: &UdpSocket, buf: &[u8]) -> io::Result<usize> {
+    timeout(UDP_TIMEOUT, socket.send(buf))
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "udp send timed out"))?
+}
+
+async fn recv_with_timeout(socket: &UdpSocket, buf: &mut [u8]) -> io::Result<usize> {
+    timeout(UDP_TIMEOUT, socket.recv(buf))
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "udp recv timed out"))?
+}
+
+fn unix_timestamp() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_hash(byte: u8) -> InfoHash {
+        InfoHash([byte; 20])
+    }
+
+    // stands in for the tracker: binds its own loopback socket, hands the client its address
+    // to `connect()` against, and lets the test script the response bytes
+    async fn fake_tracker() -> (UdpSocket, std::net::SocketAddr) {
+        let tracker = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = tracker.local_addr().unwrap();
+        (tracker, addr)
+    }
+
+    #[tokio::test]
+    async fn connect_round_trips_through_the_real_wire_format() {
+        let (tracker, tracker_addr) = fake_tracker().await;
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        client.connect(tracker_addr).await.unwrap();
+
+        let fake = tokio::spawn(async move {
+            let mut buf = [0u8; 16];
+            let (n, peer) = tracker.recv_from(&mut buf).await.unwrap();
+            let request = &buf[..n];
+
+            assert_eq!(request.len(), 16);
+            assert_eq!(&request[0..8], &PROTOCOL_ID.to_be_bytes());
+            assert_eq!(u32::from_be_bytes(request[8..12].try_into().unwrap()), ACTION_CONNECT);
+            let transaction_id = u32::from_be_bytes(request[12..16].try_into().unwrap());
+
+            let mut response = Vec::with_capacity(16);
+            response.extend_from_slice(&ACTION_CONNECT.to_be_bytes());
+            response.extend_from_slice(&transaction_id.to_be_bytes());
+            response.extend_from_slice(&0xdead_beef_cafe_babeu64.to_be_bytes());
+            tracker.send_to(&response, peer).await.unwrap();
+        });
+
+        let connection_id = connect(&client).await.unwrap();
+        fake.await.unwrap();
+
+        assert_eq!(connection_id, 0xdead_beef_cafe_babe);
+    }
+
+    #[tokio::test]
+    async fn scrape_round_trips_seeders_completed_leechers_through_the_real_wire_format() {
+        let (tracker, tracker_addr) = fake_tracker().await;
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        client.connect(tracker_addr).await.unwrap();
+
+        let info_hashes = vec![sample_hash(0xaa), sample_hash(0xbb)];
+        let expected_hashes = info_hashes.clone();
+
+        let fake = tokio::spawn(async move {
+            let mut buf = vec![0u8; 16 + expected_hashes.len() * 20];
+            let (n, peer) = tracker.recv_from(&mut buf).await.unwrap();
+            let request = &buf[..n];
+
+            assert_eq!(request.len(), 16 + expected_hashes.len() * 20);
+            assert_eq!(
+                u64::from_be_bytes(request[0..8].try_into().unwrap()),
+                0xdead_beef_cafe_babe
+            );
+            assert_eq!(u32::from_be_bytes(request[8..12].try_into().unwrap()), ACTION_SCRAPE);
+            let transaction_id = u32::from_be_bytes(request[12..16].try_into().unwrap());
+            for (i, hash) in expected_hashes.iter().enumerate() {
+                assert_eq!(&request[16 + i * 20..16 + (i + 1) * 20], &hash.0);
+            }
+
+            // two (seeders, completed, leechers) triples, one per requested hash
+            let mut response = Vec::new();
+            response.extend_from_slice(&ACTION_SCRAPE.to_be_bytes());
+            response.extend_from_slice(&transaction_id.to_be_bytes());
+            for triple in [(11u32, 22u32, 33u32), (44, 55, 66)] {
+                response.extend_from_slice(&triple.0.to_be_bytes());
+                response.extend_from_slice(&triple.1.to_be_bytes());
+                response.extend_from_slice(&triple.2.to_be_bytes());
+            }
+            tracker.send_to(&response, peer).await.unwrap();
+        });
+
+        let results = scrape(&client, 0xdead_beef_cafe_babe, &info_hashes).await.unwrap();
+        fake.await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].seeders, 11);
+        assert_eq!(results[0].completed, 22);
+        assert_eq!(results[0].leechers, 33);
+        assert_eq!(results[1].seeders, 44);
+        assert_eq!(results[1].completed, 55);
+        assert_eq!(results[1].leechers, 66);
+    }
+
+    #[test]
+    fn fresh_connection_is_within_ttl_and_stale_one_is_past_it() {
+        let fresh = Connection {
+            id: 1,
+            obtained_at: Instant::now(),
+        };
+        assert!(fresh.obtained_at.elapsed() < CONNECTION_ID_TTL);
+
+        let stale = Connection {
+            id: 2,
+            obtained_at: Instant::now() - CONNECTION_ID_TTL - Duration::from_secs(1),
+        };
+        assert!(stale.obtained_at.elapsed() >= CONNECTION_ID_TTL);
+    }
+}
@@ -1,15 +1,17 @@
 use crate::common::WebAppData;
 use crate::errors::{ServiceError, ServiceResult};
+use crate::models::info_hash::{InfoHash, InfoHashV2};
 use crate::models::response::{NewTorrentResponse, OkResponse, TorrentResponse, TorrentsResponse};
 use crate::models::torrent::{TorrentListing, TorrentRequest};
 use crate::models::torrent_file::{File, Torrent};
+use crate::models::tracker_mode::TrackerMode;
 use crate::utils::parse_torrent;
 use crate::AsCSV;
 use actix_multipart::Multipart;
 use actix_web::web::Query;
 use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use futures::{AsyncWriteExt, StreamExt, TryStreamExt};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use std::io::Cursor;
 use std::io::Write;
@@ -19,7 +21,9 @@ pub fn init_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/torrent")
             .service(web::resource("/upload").route(web::post().to(upload_torrent)))
+            .service(web::resource("/import").route(web::post().to(import_torrents)))
             .service(web::resource("/download/{id}").route(web::get().to(download_torrent)))
+            .service(web::resource("/{id}/approve").route(web::post().to(approve_torrent)))
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(get_torrent))
@@ -64,78 +68,140 @@ impl CreateTorrent {
     }
 }
 
-// eg: /torrents?categories=music,other,movie&search=bunny&sort=size_DESC
+// one row of the `infohash,name,size_bytes,seeders,leechers,category` bulk import CSV
+#[derive(Debug, Deserialize)]
+struct ImportRecord {
+    infohash: String,
+    name: String,
+    size_bytes: i64,
+    seeders: i64,
+    leechers: i64,
+    category: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportSummary {
+    pub imported: u32,
+    pub skipped: u32,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MagnetResponse {
+    pub magnet_link: String,
+}
+
+// eg: /torrents?categories=music,other,movie&search=bunny&sort=relevance
+// seeders/leechers here come straight from torrust_torrents; they're kept fresh by the
+// periodic bulk-scrape background task (see bulk_scrape.rs) rather than refreshed per request.
+// `search` is matched against the `torrust_torrents_fts` FTS5 index over title/description
+// rather than a `LIKE '%...%'` scan, which also unlocks `sort=relevance` (FTS5 bm25 rank).
 pub async fn get_torrents(
+    req: HttpRequest,
     params: Query<DisplayInfo>,
     app_data: WebAppData,
 ) -> ServiceResult<impl Responder> {
+    // Private mode requires an authenticated user for every read, including listing
+    if mode_requires_auth_for_read(app_data.cfg.settings.read().await.tracker.mode) {
+        app_data.auth.get_user_from_request(&req).await?;
+    }
+
     let page = params.page.unwrap_or(0);
     let page_size = params.page_size.unwrap_or(30);
     let offset = page * page_size;
     let categories = params.categories.as_csv::<String>().unwrap_or(None);
-    let search = match &params.search {
-        None => "%".to_string(),
-        Some(v) => format!("%{}%", v),
-    };
 
-    let sort_query: String = match &params.sort {
-        Some(sort) => match sort.as_str() {
-            "uploaded_ASC" => "upload_date ASC".to_string(),
-            "uploaded_DESC" => "upload_date DESC".to_string(),
-            "seeders_ASC" => "seeders ASC".to_string(),
-            "seeders_DESC" => "seeders DESC".to_string(),
-            "leechers_ASC" => "leechers ASC".to_string(),
-            "leechers_DESC" => "leechers DESC".to_string(),
-            "name_ASC" => "title ASC".to_string(),
-            "name_DESC" => "title DESC".to_string(),
-            "size_ASC" => "file_size ASC".to_string(),
-            "size_DESC" => "file_size DESC".to_string(),
-            _ => "upload_date DESC".to_string(),
-        },
-        None => "upload_date DESC".to_string(),
-    };
-
-    let category_filter_query = if let Some(c) = categories {
-        let mut i = 0;
-        let mut category_filters = String::new();
+    // don't take user input straight into the query; only bind names the db recognizes
+    let mut sanitized_categories = Vec::new();
+    if let Some(c) = categories {
         for category in c.iter() {
-            // don't take user input in the db query
-            if let Some(sanitized_category) = &app_data.database.verify_category(category).await {
-                let mut str = format!("tc.name = '{}'", sanitized_category);
-                if i > 0 {
-                    str = format!(" OR {}", str);
-                }
-                category_filters.push_str(&str);
-                i += 1;
+            if let Some(sanitized_category) = app_data.database.verify_category(category).await {
+                sanitized_categories.push(sanitized_category);
             }
         }
-        if category_filters.len() > 0 {
-            format!(
-                "INNER JOIN torrust_categories tc ON tt.category_id = tc.category_id AND ({})",
-                category_filters
-            )
-        } else {
-            String::new()
-        }
-    } else {
+    }
+
+    let category_join = if sanitized_categories.is_empty() {
         String::new()
+    } else {
+        let placeholders = vec!["?"; sanitized_categories.len()].join(", ");
+        format!(
+            " INNER JOIN torrust_categories tc ON tt.category_id = tc.category_id AND tc.name IN ({})",
+            placeholders
+        )
     };
 
-    let mut query_string = format!(
-        "SELECT tt.* FROM torrust_torrents tt {} WHERE title LIKE ?",
-        category_filter_query
-    );
-    let count_query_string = format!("SELECT COUNT(torrent_id) as count FROM ({})", query_string);
+    // title/description full-text index; an empty search term matches everything, so there's
+    // nothing to rank and we fall back to the non-relevance sort modes below
+    let has_search_term = params.search.as_ref().map_or(false, |s| !s.trim().is_empty());
+    // quoting the whole term as one FTS5 string literal (doubling embedded quotes) makes it
+    // a literal phrase match instead of a query expression, so punctuation, a leading `-`, or
+    // keywords like AND/OR/NOT/NEAR in user input can't be parsed as FTS5 operators and 500
+    let search_term = params
+        .search
+        .as_ref()
+        .map(|s| format!("\"{}\"", s.trim().replace('"', "\"\"")));
+
+    let search_join = if has_search_term {
+        " INNER JOIN torrust_torrents_fts fts ON fts.rowid = tt.torrent_id"
+    } else {
+        ""
+    };
+    let search_where = if has_search_term {
+        "fts.torrust_torrents_fts MATCH ?"
+    } else {
+        "1"
+    };
 
-    let count: TorrentCount = sqlx::query_as::<_, TorrentCount>(&count_query_string)
-        .bind(search.clone())
-        .fetch_one(&app_data.database.pool)
-        .await?;
+    let sort_query: String = match params.sort.as_deref() {
+        Some("uploaded_ASC") => "tt.upload_date ASC".to_string(),
+        Some("uploaded_DESC") => "tt.upload_date DESC".to_string(),
+        Some("seeders_ASC") => "tt.seeders ASC".to_string(),
+        Some("seeders_DESC") => "tt.seeders DESC".to_string(),
+        Some("leechers_ASC") => "tt.leechers ASC".to_string(),
+        Some("leechers_DESC") => "tt.leechers DESC".to_string(),
+        Some("name_ASC") => "tt.title ASC".to_string(),
+        Some("name_DESC") => "tt.title DESC".to_string(),
+        Some("size_ASC") => "tt.file_size ASC".to_string(),
+        Some("size_DESC") => "tt.file_size DESC".to_string(),
+        Some("relevance") if has_search_term => "fts.rank".to_string(),
+        _ => "tt.upload_date DESC".to_string(),
+    };
 
-    query_string = format!("{} ORDER BY {} LIMIT ?, ?", query_string, sort_query);
+    // snippet() highlights where the search term matched so the UI can show it inline;
+    // meaningless without a search term, so just report no match there
+    let snippet_column = if has_search_term {
+        "snippet(torrust_torrents_fts, -1, '<b>', '</b>', '...', 8) AS match_snippet"
+    } else {
+        "NULL AS match_snippet"
+    };
 
-    let res: Vec<TorrentListing> = sqlx::query_as::<_, TorrentListing>(&query_string)
-        .bind(search)
+    let query_string = format!(
+        "SELECT tt.*, {} FROM torrust_torrents tt{}{} WHERE {} ORDER BY {} LIMIT ?, ?",
+        snippet_column, category_join, search_join, search_where, sort_query
+    );
+    let count_query_string = format!(
+        "SELECT COUNT(*) as count FROM torrust_torrents tt{}{} WHERE {}",
+        category_join, search_join, search_where
+    );
+
+    let mut count_query = sqlx::query_as::<_, TorrentCount>(&count_query_string);
+    for category in &sanitized_categories {
+        count_query = count_query.bind(category);
+    }
+    if has_search_term {
+        count_query = count_query.bind(search_term.as_ref().unwrap());
+    }
+    let count: TorrentCount = count_query.fetch_one(&app_data.database.pool).await?;
+
+    let mut query = sqlx::query_as::<_, TorrentListing>(&query_string);
+    for category in &sanitized_categories {
+        query = query.bind(category);
+    }
+    if has_search_term {
+        query = query.bind(search_term.as_ref().unwrap());
+    }
+    let res: Vec<TorrentListing> = query
         .bind(offset)
         .bind(page_size)
         .fetch_all(&app_data.database.pool)
@@ -152,10 +218,16 @@ pub async fn get_torrents(
 }
 
 pub async fn get_torrent(req: HttpRequest, app_data: WebAppData) -> ServiceResult<impl Responder> {
-    // optional
+    // optional, unless the tracker is running in Private mode (checked below)
     let user = app_data.auth.get_user_from_request(&req).await;
 
     let settings = app_data.cfg.settings.read().await;
+    let tracker_mode = settings.tracker.mode;
+
+    // Private mode requires an authenticated user for every read, not just downloads
+    if mode_requires_auth_for_read(tracker_mode) && user.is_err() {
+        return Err(ServiceError::Unauthorized);
+    }
 
     let torrent_id = get_torrent_id_from_request(&req)?;
 
@@ -172,6 +244,10 @@ pub async fn get_torrent(req: HttpRequest, app_data: WebAppData) -> ServiceResul
 
     drop(settings);
 
+    // v2/hybrid torrents carry a second, 32-byte SHA-256 info-hash alongside the v1 one;
+    // only known once the .torrent file itself has been parsed
+    let mut info_hash_v2: Option<InfoHashV2> = None;
+
     if let Ok(torrent) = parse_torrent::read_torrent_from_file(&filepath) {
         // add torrent file/files to response
         if let Some(files) = torrent.info.files {
@@ -193,6 +269,8 @@ pub async fn get_torrent(req: HttpRequest, app_data: WebAppData) -> ServiceResul
                 torrent_response.trackers.push(tracker[0].clone());
             }
         }
+
+        info_hash_v2 = torrent.info_hash_v2();
     }
 
     // add self-hosted tracker url
@@ -209,10 +287,16 @@ pub async fn get_torrent(req: HttpRequest, app_data: WebAppData) -> ServiceResul
         torrent_response.trackers.insert(0, tracker_url);
     }
 
-    // add magnet link
+    // add magnet link: v1-only torrents use the classic btih urn, v2 and hybrid torrents
+    // advertise the multihash-prefixed (0x1220 = sha2-256, 32 bytes) v2 hash instead so
+    // v2-aware clients don't fall back to resolving the v1 hash over DHT
+    let xt = match info_hash_v2 {
+        Some(info_hash_v2) => format!("urn:btmh:1220{}", info_hash_v2),
+        None => format!("urn:btih:{}", torrent_response.info_hash),
+    };
     let mut magnet = format!(
-        "magnet:?xt=urn:btih:{}&dn={}",
-        torrent_response.info_hash,
+        "magnet:?xt={}&dn={}",
+        xt,
         urlencoding::encode(&torrent_response.title)
     );
     // add trackers from torrent file to magnet link
@@ -314,6 +398,31 @@ pub async fn delete_torrent(
     }))
 }
 
+// admin-only: whitelists the info hash of a torrent that was uploaded while the tracker is
+// running in `Static`/`Private` mode, where `upload_torrent` no longer auto-whitelists
+pub async fn approve_torrent(
+    req: HttpRequest,
+    app_data: WebAppData,
+) -> ServiceResult<impl Responder> {
+    let user = app_data.auth.get_user_from_request(&req).await?;
+
+    if !user.administrator {
+        return Err(ServiceError::Unauthorized);
+    }
+
+    let torrent_id = get_torrent_id_from_request(&req)?;
+    let torrent_listing = app_data.database.get_torrent_by_id(torrent_id).await?;
+
+    app_data
+        .tracker
+        .whitelist_info_hash(torrent_listing.info_hash)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(OkResponse {
+        data: NewTorrentResponse { torrent_id },
+    }))
+}
+
 pub async fn upload_torrent(
     req: HttpRequest,
     payload: Multipart,
@@ -342,7 +451,9 @@ pub async fn upload_torrent(
     };
 
     let username = user.username;
+    // v1 hash is always present; v2/hybrid torrents also carry a 32-byte SHA-256 v2 hash
     let info_hash = torrent_request.torrent.info_hash();
+    let info_hash_v2 = torrent_request.torrent.info_hash_v2();
     let title = torrent_request.fields.title;
     //let category = torrent_request.fields.category;
     let description = torrent_request.fields.description;
@@ -356,11 +467,13 @@ pub async fn upload_torrent(
         leechers = torrent_info.leechers;
     }
 
+    // store both hashes so a lookup by either the v1 or the v2 hash resolves the same listing
     let torrent_id = app_data
         .database
         .insert_torrent_and_get_id(
             username,
             info_hash,
+            info_hash_v2,
             title,
             row.category_id,
             description,
@@ -370,19 +483,23 @@ pub async fn upload_torrent(
         )
         .await?;
 
-    // whitelist info hash on tracker
-    let _ = app_data
-        .tracker
-        .whitelist_info_hash(torrent_request.torrent.info_hash())
-        .await;
-
     let settings = app_data.cfg.settings.read().await;
 
     let upload_folder = settings.storage.upload_path.clone();
     let filepath = format!("{}/{}", upload_folder, torrent_id.to_string() + ".torrent");
+    let tracker_mode = settings.tracker.mode;
 
     drop(settings);
 
+    // Dynamic mode whitelists every upload immediately, as before. Static and Private modes
+    // leave the info hash un-whitelisted until an admin approves it via `approve_torrent`.
+    if tracker_mode == TrackerMode::Dynamic {
+        let _ = app_data
+            .tracker
+            .whitelist_info_hash(torrent_request.torrent.info_hash())
+            .await;
+    }
+
     save_torrent_file(&upload_folder, &filepath, &torrent_request.torrent).await?;
 
     Ok(HttpResponse::Ok().json(OkResponse {
@@ -390,6 +507,98 @@ pub async fn upload_torrent(
     }))
 }
 
+// bulk-seeds the index straight from a flat `infohash,name,size_bytes,seeders,leechers,category`
+// CSV, creating listings that have no backing .torrent file on disk (see `download_torrent` and
+// `get_torrent` for how those magnet-only entries are served back out)
+pub async fn import_torrents(
+    req: HttpRequest,
+    body: web::Bytes,
+    app_data: WebAppData,
+) -> ServiceResult<impl Responder> {
+    let user = app_data.auth.get_user_from_request(&req).await?;
+
+    // bootstrapping a large index from an existing dataset is an admin-only operation
+    if !user.administrator {
+        return Err(ServiceError::Unauthorized);
+    }
+
+    let mut reader = csv::ReaderBuilder::new().from_reader(body.as_ref());
+
+    let mut imported = 0u32;
+    let mut skipped = 0u32;
+    let mut errors = Vec::new();
+
+    for (i, record) in reader.deserialize::<ImportRecord>().enumerate() {
+        let row_num = i + 2; // account for the header row
+
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                skipped += 1;
+                errors.push(format!("row {}: {}", row_num, e));
+                continue;
+            }
+        };
+
+        let info_hash = match record.infohash.parse::<InfoHash>() {
+            Ok(info_hash) => info_hash,
+            Err(_) => {
+                skipped += 1;
+                errors.push(format!("row {}: invalid infohash `{}`", row_num, record.infohash));
+                continue;
+            }
+        };
+
+        let res = sqlx::query!(
+            "SELECT category_id FROM torrust_categories WHERE name = ?",
+            record.category
+        )
+        .fetch_one(&app_data.database.pool)
+        .await;
+
+        let category_id = match res {
+            Ok(row) => row.category_id,
+            Err(_) => {
+                skipped += 1;
+                errors.push(format!("row {}: unknown category `{}`", row_num, record.category));
+                continue;
+            }
+        };
+
+        // CSV rows only ever carry a v1 hash; there's no v2/hybrid equivalent to import
+        let inserted = app_data
+            .database
+            .insert_torrent_and_get_id(
+                user.username.clone(),
+                info_hash,
+                None,
+                record.name,
+                category_id,
+                String::new(),
+                record.size_bytes,
+                record.seeders,
+                record.leechers,
+            )
+            .await;
+
+        match inserted {
+            Ok(_) => imported += 1,
+            Err(e) => {
+                skipped += 1;
+                errors.push(format!("row {}: {}", row_num, e));
+            }
+        }
+    }
+
+    Ok(HttpResponse::Ok().json(OkResponse {
+        data: ImportSummary {
+            imported,
+            skipped,
+            errors,
+        },
+    }))
+}
+
 pub async fn download_torrent(
     req: HttpRequest,
     app_data: WebAppData,
@@ -397,6 +606,7 @@ pub async fn download_torrent(
     let torrent_id = get_torrent_id_from_request(&req)?;
 
     let settings = app_data.cfg.settings.read().await;
+    let tracker_mode = settings.tracker.mode;
 
     // optional
     let user = app_data.auth.get_user_from_request(&req).await;
@@ -407,46 +617,73 @@ pub async fn download_torrent(
         torrent_id.to_string() + ".torrent"
     );
 
-    let mut torrent = match parse_torrent::read_torrent_from_file(&filepath) {
-        Ok(torrent) => Ok(torrent),
-        Err(e) => {
-            println!("{:?}", e);
-            Err(ServiceError::InternalServerError)
-        }
-    }?;
+    drop(settings);
 
     if user.is_ok() {
         let unwrapped_user = user.unwrap();
+
+        // Static and Private mode require admin approval (see `approve_torrent`) before a
+        // freshly-uploaded info hash is served; Dynamic mode whitelists on upload already
+        if mode_requires_whitelist_check(tracker_mode) {
+            let torrent_listing = app_data.database.get_torrent_by_id(torrent_id).await?;
+            if !app_data
+                .tracker
+                .is_info_hash_whitelisted(&torrent_listing.info_hash)
+                .await
+                .unwrap_or(false)
+            {
+                return Err(ServiceError::TorrentNotFound);
+            }
+        }
+
         let personal_announce_url = app_data
             .tracker
             .get_personal_announce_url(&unwrapped_user)
             .await?;
-        torrent.announce = Some(personal_announce_url.clone());
-        if let Some(list) = &mut torrent.announce_list {
-            let mut vec = Vec::new();
-            vec.push(personal_announce_url);
-            list.insert(0, vec);
-        }
-        drop(settings);
 
-        let buffer = match parse_torrent::encode_torrent(&torrent) {
-            Ok(v) => Ok(v),
-            Err(e) => {
-                println!("{:?}", e);
-                Err(ServiceError::InternalServerError)
-            }
-        }?;
+        match parse_torrent::read_torrent_from_file(&filepath) {
+            Ok(mut torrent) => {
+                torrent.announce = Some(personal_announce_url.clone());
+                if let Some(list) = &mut torrent.announce_list {
+                    let mut vec = Vec::new();
+                    vec.push(personal_announce_url);
+                    list.insert(0, vec);
+                }
 
-        Ok(HttpResponse::Ok()
-            .content_type("application/x-bittorrent")
-            .body(buffer))
+                let buffer = match parse_torrent::encode_torrent(&torrent) {
+                    Ok(v) => Ok(v),
+                    Err(e) => {
+                        println!("{:?}", e);
+                        Err(ServiceError::InternalServerError)
+                    }
+                }?;
+
+                Ok(HttpResponse::Ok()
+                    .content_type("application/x-bittorrent")
+                    .body(buffer))
+            }
+            Err(_) => {
+                // no .torrent file on disk, e.g. a listing bootstrapped via /torrent/import:
+                // fall back to a magnet link built from the stored info hash instead of 500ing
+                let torrent_listing = app_data.database.get_torrent_by_id(torrent_id).await?;
+                let magnet = format!(
+                    "magnet:?xt=urn:btih:{}&dn={}&tr={}",
+                    torrent_listing.info_hash,
+                    urlencoding::encode(&torrent_listing.title),
+                    urlencoding::encode(&personal_announce_url)
+                );
+
+                Ok(HttpResponse::Ok().json(OkResponse {
+                    data: MagnetResponse { magnet_link: magnet },
+                }))
+            }
+        }
     } else {
         if let Err(error) = user {
             Err(error)
         } else {
             Err(ServiceError::Unauthorized)
         }
-        // torrent.announce = Some(settings.tracker.url.clone());
     }
 }
 
@@ -483,6 +720,18 @@ async fn save_torrent_file(
     Ok(())
 }
 
+// Whether `mode` requires an authenticated caller before a read (`get_torrents`/`get_torrent`)
+// is allowed to return torrent metadata. Only `Private` does; `Dynamic`/`Static` stay public.
+fn mode_requires_auth_for_read(mode: TrackerMode) -> bool {
+    mode == TrackerMode::Private
+}
+
+// Whether `mode` requires `download_torrent` to check the tracker whitelist before serving a
+// torrent. `Dynamic` auto-whitelists on upload, so only `Static`/`Private` need the check.
+fn mode_requires_whitelist_check(mode: TrackerMode) -> bool {
+    mode != TrackerMode::Dynamic
+}
+
 fn get_torrent_id_from_request(req: &HttpRequest) -> Result<i64, ServiceError> {
     match req.match_info().get("id") {
         None => Err(ServiceError::BadRequest),
@@ -558,3 +807,86 @@ async fn get_torrent_request_from_payload(
 
     Ok(TorrentRequest { fields, torrent })
 }
+
+#[cfg(test)]
+mod import_tests {
+    use super::*;
+
+    #[test]
+    fn valid_import_row_parses_into_record() {
+        let csv = "infohash,name,size_bytes,seeders,leechers,category\n\
+                    0123456789abcdef0123456789abcdef01234567,Sample,1024,5,2,movies\n";
+
+        let mut reader = csv::ReaderBuilder::new().from_reader(csv.as_bytes());
+        let record: ImportRecord = reader
+            .deserialize()
+            .next()
+            .expect("one row")
+            .expect("row parses");
+
+        assert_eq!(record.infohash, "0123456789abcdef0123456789abcdef01234567");
+        assert_eq!(record.name, "Sample");
+        assert_eq!(record.size_bytes, 1024);
+        assert_eq!(record.seeders, 5);
+        assert_eq!(record.leechers, 2);
+        assert_eq!(record.category, "movies");
+    }
+
+    #[test]
+    fn non_numeric_size_fails_to_deserialize() {
+        let csv = "infohash,name,size_bytes,seeders,leechers,category\n\
+                    0123456789abcdef0123456789abcdef01234567,Sample,not-a-number,5,2,movies\n";
+
+        let mut reader = csv::ReaderBuilder::new().from_reader(csv.as_bytes());
+        let record: Result<ImportRecord, _> = reader.deserialize().next().expect("one row");
+
+        assert!(record.is_err());
+    }
+
+    #[test]
+    fn short_infohash_is_rejected_at_the_type_boundary() {
+        assert!("deadbeef".parse::<InfoHash>().is_err());
+    }
+
+    #[test]
+    fn non_hex_infohash_is_rejected_at_the_type_boundary() {
+        let not_hex = "zz23456789abcdef0123456789abcdef0123456z";
+        assert!(not_hex.parse::<InfoHash>().is_err());
+    }
+
+    #[test]
+    fn valid_infohash_round_trips_through_display() {
+        let hash: InfoHash = "0123456789abcdef0123456789abcdef01234567".parse().unwrap();
+        assert_eq!(hash.to_string(), "0123456789abcdef0123456789abcdef01234567");
+    }
+}
+
+// covers the predicates `get_torrents`/`get_torrent`/`download_torrent` gate their auth and
+// whitelist checks on, since those handlers need a live `WebAppData` (db pool, tracker client)
+// this repo has no harness for in unit tests
+#[cfg(test)]
+mod tracker_mode_tests {
+    use super::*;
+
+    #[test]
+    fn private_mode_requires_auth_for_reads() {
+        assert!(mode_requires_auth_for_read(TrackerMode::Private));
+    }
+
+    #[test]
+    fn dynamic_and_static_modes_allow_anonymous_reads() {
+        assert!(!mode_requires_auth_for_read(TrackerMode::Dynamic));
+        assert!(!mode_requires_auth_for_read(TrackerMode::Static));
+    }
+
+    #[test]
+    fn static_and_private_modes_require_a_whitelist_check_before_download() {
+        assert!(mode_requires_whitelist_check(TrackerMode::Static));
+        assert!(mode_requires_whitelist_check(TrackerMode::Private));
+    }
+
+    #[test]
+    fn dynamic_mode_skips_the_whitelist_check_before_download() {
+        assert!(!mode_requires_whitelist_check(TrackerMode::Dynamic));
+    }
+}
@@ -0,0 +1,36 @@
+mod bulk_scrape;
+mod common;
+mod config;
+mod errors;
+mod handlers;
+mod models;
+mod utils;
+
+use std::time::Duration;
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let app_data = common::build_app_data().await;
+
+    // periodic BEP15 bulk-scrape keeps seeders/leechers fresh without hitting the tracker
+    // once per page load (see bulk_scrape.rs)
+    let bulk_scrape_interval = {
+        let settings = app_data.cfg.settings.read().await;
+        Duration::from_secs(settings.tracker.bulk_scrape_interval)
+    };
+    bulk_scrape::spawn(app_data.clone(), bulk_scrape_interval);
+
+    let bind_addr = {
+        let settings = app_data.cfg.settings.read().await;
+        settings.net.bind_address.clone()
+    };
+
+    actix_web::HttpServer::new(move || {
+        actix_web::App::new()
+            .app_data(app_data.clone())
+            .configure(handlers::torrent::init_routes)
+    })
+    .bind(bind_addr)?
+    .run()
+    .await
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub const INFO_HASH_V1_BYTE_LEN: usize = 20;
+pub const INFO_HASH_V2_BYTE_LEN: usize = 32;
+
+/// The v1 info-hash: SHA-1 of the bencoded `info` dict, strictly 20 bytes.
+///
+/// This mirrors the tracker's own `InfoHash` (same 40-hex-char wire format) so that a hash
+/// flowing between the index and the tracker never degrades back into a loosely-typed
+/// `String` along the way.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct InfoHash(pub [u8; INFO_HASH_V1_BYTE_LEN]);
+
+/// The v2 info-hash: full SHA-256 of the bencoded v2 `info` dict, 32 bytes.
+///
+/// Present on v2-only and hybrid torrents alongside the v1 hash.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct InfoHashV2(pub [u8; INFO_HASH_V2_BYTE_LEN]);
+
+impl fmt::Display for InfoHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buff = [0u8; INFO_HASH_V1_BYTE_LEN * 2];
+        binascii::bin2hex(&self.0, &mut buff).unwrap();
+        write!(f, "{}", std::str::from_utf8(&buff).unwrap())
+    }
+}
+
+impl fmt::Display for InfoHashV2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buff = [0u8; INFO_HASH_V2_BYTE_LEN * 2];
+        binascii::bin2hex(&self.0, &mut buff).unwrap();
+        write!(f, "{}", std::str::from_utf8(&buff).unwrap())
+    }
+}
+
+impl FromStr for InfoHash {
+    type Err = binascii::ConvertError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut i = Self(Default::default());
+        if s.len() != INFO_HASH_V1_BYTE_LEN * 2 {
+            return Err(binascii::ConvertError::InvalidInputLength);
+        }
+        binascii::hex2bin(s.as_bytes(), &mut i.0)?;
+        Ok(i)
+    }
+}
+
+impl FromStr for InfoHashV2 {
+    type Err = binascii::ConvertError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut i = Self([0u8; INFO_HASH_V2_BYTE_LEN]);
+        if s.len() != INFO_HASH_V2_BYTE_LEN * 2 {
+            return Err(binascii::ConvertError::InvalidInputLength);
+        }
+        binascii::hex2bin(s.as_bytes(), &mut i.0)?;
+        Ok(i)
+    }
+}
+
+impl TryFrom<String> for InfoHash {
+    type Error = binascii::ConvertError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl serde::Serialize for InfoHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for InfoHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        InfoHash::from_str(&s).map_err(|_| serde::de::Error::custom("invalid info hash"))
+    }
+}
+
+impl serde::Serialize for InfoHashV2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for InfoHashV2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        InfoHashV2::from_str(&s).map_err(|_| serde::de::Error::custom("invalid v2 info hash"))
+    }
+}
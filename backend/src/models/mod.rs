@@ -0,0 +1,5 @@
+pub mod info_hash;
+pub mod response;
+pub mod torrent;
+pub mod torrent_file;
+pub mod tracker_mode;
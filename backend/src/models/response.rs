@@ -0,0 +1,61 @@
+use crate::models::info_hash::InfoHash;
+use crate::models::torrent::TorrentListing;
+use crate::models::torrent_file::File;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct OkResponse<T> {
+    pub data: T,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NewTorrentResponse {
+    pub torrent_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TorrentsResponse {
+    pub total: u32,
+    pub results: Vec<TorrentListing>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TorrentResponse {
+    pub torrent_id: i64,
+    pub uploader: String,
+    pub info_hash: InfoHash,
+    pub title: String,
+    pub description: Option<String>,
+    pub category_id: i64,
+    pub upload_date: i64,
+    pub file_size: i64,
+    pub seeders: i64,
+    pub leechers: i64,
+    pub files: Option<Vec<File>>,
+    pub trackers: Vec<String>,
+    pub magnet_link: String,
+    // carried over from the `TorrentListing` this response was built from so the UI can
+    // highlight where a search term matched; `None` outside of search results
+    pub match_snippet: Option<String>,
+}
+
+impl TorrentResponse {
+    pub fn from_listing(listing: TorrentListing) -> Self {
+        Self {
+            torrent_id: listing.torrent_id,
+            uploader: listing.uploader,
+            info_hash: listing.info_hash,
+            title: listing.title,
+            description: listing.description,
+            category_id: listing.category_id,
+            upload_date: listing.upload_date,
+            file_size: listing.file_size,
+            seeders: listing.seeders,
+            leechers: listing.leechers,
+            files: None,
+            trackers: Vec::new(),
+            magnet_link: String::new(),
+            match_snippet: listing.match_snippet,
+        }
+    }
+}
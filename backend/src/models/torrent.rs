@@ -0,0 +1,29 @@
+use crate::handlers::torrent::CreateTorrent;
+use crate::models::info_hash::InfoHash;
+use crate::models::torrent_file::Torrent;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct TorrentListing {
+    pub torrent_id: i64,
+    pub uploader: String,
+    #[sqlx(try_from = "String")]
+    pub info_hash: InfoHash,
+    pub title: String,
+    pub category_id: i64,
+    pub description: Option<String>,
+    pub upload_date: i64,
+    pub file_size: i64,
+    pub seeders: i64,
+    pub leechers: i64,
+    // only populated by the FTS5-backed search query in `get_torrents`; every other query
+    // that returns a `TorrentListing` (e.g. `get_torrent_by_id`) has no such column to read
+    #[sqlx(default)]
+    pub match_snippet: Option<String>,
+}
+
+pub struct TorrentRequest {
+    pub fields: CreateTorrent,
+    pub torrent: Torrent,
+}
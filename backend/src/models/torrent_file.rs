@@ -0,0 +1,123 @@
+use crate::models::info_hash::{InfoHash, InfoHashV2};
+use serde::{Deserialize, Serialize};
+use serde_bencode::value::Value as BencodeValue;
+use sha1::{Digest as Sha1Digest, Sha1};
+use sha2::{Digest as Sha2Digest, Sha256};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct File {
+    pub path: Vec<String>,
+    pub length: i64,
+    pub md5sum: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Info {
+    pub name: String,
+    #[serde(default)]
+    pub length: Option<i64>,
+    #[serde(default)]
+    pub files: Option<Vec<File>>,
+    #[serde(rename = "piece length", default)]
+    pub piece_length: Option<i64>,
+    // v1 piece hashes; presence alongside `file_tree` marks a hybrid torrent
+    #[serde(default)]
+    pub pieces: Option<serde_bytes::ByteBuf>,
+    // BEP52 v2 layout: a nested dict of { path segment -> { ... -> { "" -> { length, pieces root } } } }.
+    // Kept as an opaque bencode value purely so it round-trips byte-for-byte through hashing;
+    // the index doesn't need to walk it.
+    #[serde(rename = "file tree", default)]
+    pub file_tree: Option<BencodeValue>,
+    #[serde(rename = "meta version", default)]
+    pub meta_version: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Torrent {
+    pub info: Info,
+    #[serde(default)]
+    pub announce: Option<String>,
+    #[serde(rename = "announce-list", default)]
+    pub announce_list: Option<Vec<Vec<String>>>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(rename = "created by", default)]
+    pub created_by: Option<String>,
+    #[serde(rename = "creation date", default)]
+    pub creation_date: Option<i64>,
+    // The `info` dict's own bencode bytes, re-encoded from the untyped value parsed out of the
+    // uploaded `.torrent` (identical to the original for a canonically encoded file).
+    // `Info` only models the keys this code understands, so hashing a re-encoding of it would
+    // silently drop keys it doesn't know about (BEP27 `private`, `source`, tracker-specific
+    // extensions) and produce an info-hash that doesn't match the real torrent. Hashing these
+    // raw bytes instead means the hash is correct regardless of what the `info` dict contains.
+    #[serde(skip)]
+    info_bytes: Vec<u8>,
+}
+
+impl Torrent {
+    /// The v1 info-hash: SHA-1 of the raw `info` dict bytes. Always present, v1 or hybrid.
+    pub fn info_hash(&self) -> InfoHash {
+        InfoHash(Sha1::digest(&self.info_bytes).into())
+    }
+
+    /// The v2 info-hash: SHA-256 of the raw `info` dict bytes. Only v2-only and hybrid
+    /// torrents carry a `file tree`, so plain v1 torrents return `None` here.
+    pub fn info_hash_v2(&self) -> Option<InfoHashV2> {
+        if self.info.file_tree.is_none() {
+            return None;
+        }
+
+        Some(InfoHashV2(Sha256::digest(&self.info_bytes).into()))
+    }
+
+    /// A hybrid torrent carries both the v1 `pieces` layout and the v2 `file tree`, so
+    /// either hash resolves it.
+    pub fn is_hybrid(&self) -> bool {
+        self.info.pieces.is_some() && self.info.file_tree.is_some()
+    }
+
+    pub fn file_size(&self) -> i64 {
+        match &self.info.files {
+            Some(files) => files.iter().map(|f| f.length).sum(),
+            None => self.info.length.unwrap_or(0),
+        }
+    }
+
+    pub async fn set_torrust_config(&mut self, cfg: &crate::config::Configuration) {
+        let settings = cfg.settings.read().await;
+        self.announce = Some(settings.tracker.url.clone());
+        self.announce_list = Some(vec![vec![settings.tracker.url.clone()]]);
+    }
+
+    /// Builds a `Torrent` from the raw `info` dict bytes used to derive its hashes, and the
+    /// typed `info` dict decoded from those same bytes for field access. Only `parse_torrent`
+    /// should need this; everywhere else should go through `decode_torrent`.
+    pub(crate) fn from_parts(info: Info, info_bytes: Vec<u8>, rest: TorrentMetadata) -> Self {
+        Self {
+            info,
+            announce: rest.announce,
+            announce_list: rest.announce_list,
+            comment: rest.comment,
+            created_by: rest.created_by,
+            creation_date: rest.creation_date,
+            info_bytes,
+        }
+    }
+}
+
+// The non-`info` fields of a `.torrent` file, decoded the normal (typed, lossy-on-unknown-keys)
+// way since none of them feed into the info-hash.
+#[derive(Deserialize)]
+pub(crate) struct TorrentMetadata {
+    #[serde(default)]
+    pub announce: Option<String>,
+    #[serde(rename = "announce-list", default)]
+    pub announce_list: Option<Vec<Vec<String>>>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(rename = "created by", default)]
+    pub created_by: Option<String>,
+    #[serde(rename = "creation date", default)]
+    pub creation_date: Option<i64>,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Mirrors the tracker's own access-control mode so the index enforces the same policy
+/// instead of drifting out of sync with it (e.g. `download_torrent` demanding auth while
+/// `get_torrent` leaked metadata to anonymous users).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackerMode {
+    /// Every uploaded info hash is whitelisted automatically. Current/default behavior.
+    Dynamic,
+    /// Uploads land in the index but are not auto-whitelisted; an admin must approve the
+    /// info hash (see `approve_torrent`) before `download_torrent` will serve it.
+    Static,
+    /// Same whitelisting rules as `Static`, plus every read (`get_torrent`, `get_torrents`)
+    /// requires an authenticated user, and the announce URL is always the caller's personal
+    /// one, never the public tracker URL.
+    Private,
+}
+
+impl Default for TrackerMode {
+    fn default() -> Self {
+        TrackerMode::Dynamic
+    }
+}
@@ -0,0 +1,33 @@
+use crate::models::torrent_file::{Info, Torrent, TorrentMetadata};
+use serde_bencode::value::Value as BencodeValue;
+use std::io;
+
+pub fn decode_torrent(bytes: &[u8]) -> Result<Torrent, serde_bencode::Error> {
+    let root: BencodeValue = serde_bencode::from_bytes(bytes)?;
+    let BencodeValue::Dict(root) = root else {
+        return Err(serde_bencode::Error::Custom(
+            "torrent file is not a bencode dict".to_string(),
+        ));
+    };
+    let info_value = root
+        .get(&b"info"[..])
+        .cloned()
+        .ok_or_else(|| serde_bencode::Error::Custom("torrent file has no \"info\" dict".to_string()))?;
+
+    // the info dict's own canonical bytes, unknown keys included, so the info-hash matches
+    // the real torrent regardless of what `Info` below knows how to model
+    let info_bytes = serde_bencode::to_bytes(&info_value)?;
+    let info: Info = serde_bencode::from_bytes(&info_bytes)?;
+    let metadata: TorrentMetadata = serde_bencode::from_bytes(bytes)?;
+
+    Ok(Torrent::from_parts(info, info_bytes, metadata))
+}
+
+pub fn encode_torrent(torrent: &Torrent) -> Result<Vec<u8>, serde_bencode::Error> {
+    serde_bencode::to_bytes(torrent)
+}
+
+pub fn read_torrent_from_file(path: &str) -> Result<Torrent, io::Error> {
+    let bytes = std::fs::read(path)?;
+    decode_torrent(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}